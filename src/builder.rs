@@ -0,0 +1,122 @@
+use crate::{Layout, Node, Variant, Version};
+
+/// Assembles a [`Layout`] from raw bytes or field values without going
+/// through a version generator, so externally generated identifiers (or
+/// FFI types such as a Windows `GUID`) can be imported as a well-formed
+/// UUID.
+pub struct Builder(Layout);
+
+impl Builder {
+    /// Starts a `Builder` from a raw 16-byte UUID.
+    pub fn from_bytes(bytes: [u8; 16]) -> Builder {
+        Builder(Layout {
+            field_low: u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            field_mid: u16::from_be_bytes([bytes[4], bytes[5]]),
+            field_high_and_version: u16::from_be_bytes([bytes[6], bytes[7]]),
+            clock_seq_high_and_reserved: bytes[8],
+            clock_seq_low: bytes[9],
+            node: Node([
+                bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+            ]),
+        })
+    }
+
+    /// Starts a `Builder` from RFC 4122 field values in big-endian order.
+    pub fn from_fields(time_low: u32, time_mid: u16, time_hi: u16, rest: &[u8; 8]) -> Builder {
+        Builder(Layout {
+            field_low: time_low,
+            field_mid: time_mid,
+            field_high_and_version: time_hi,
+            clock_seq_high_and_reserved: rest[0],
+            clock_seq_low: rest[1],
+            node: Node([rest[2], rest[3], rest[4], rest[5], rest[6], rest[7]]),
+        })
+    }
+
+    /// Starts a `Builder` from field values where `time_low`/`time_mid`/
+    /// `time_hi` are little-endian, matching the mixed-endian layout of a
+    /// Windows `GUID` struct (`Data1`/`Data2`/`Data3` native-endian,
+    /// `Data4` a plain byte array).
+    pub fn from_fields_le(time_low: u32, time_mid: u16, time_hi: u16, rest: &[u8; 8]) -> Builder {
+        Builder::from_fields(
+            time_low.swap_bytes(),
+            time_mid.swap_bytes(),
+            time_hi.swap_bytes(),
+            rest,
+        )
+    }
+
+    /// Overwrites the 4-bit version field, leaving the timestamp/random
+    /// bits it shares a field with untouched.
+    pub fn with_version(mut self, version: Version) -> Builder {
+        self.0.field_high_and_version =
+            (self.0.field_high_and_version & 0xfff) | (version as u16) << 12;
+        self
+    }
+
+    /// Overwrites the variant bits, leaving the clock sequence bits it
+    /// shares a field with untouched.
+    pub fn with_variant(mut self, variant: Variant) -> Builder {
+        self.0.clock_seq_high_and_reserved =
+            crate::pack_variant(self.0.clock_seq_high_and_reserved, variant);
+        self
+    }
+
+    /// Returns the field values assembled so far, in big-endian order.
+    pub fn as_fields(&self) -> (u32, u16, u16, [u8; 8]) {
+        (
+            self.0.field_low,
+            self.0.field_mid,
+            self.0.field_high_and_version,
+            [
+                self.0.clock_seq_high_and_reserved,
+                self.0.clock_seq_low,
+                self.0.node.0[0],
+                self.0.node.0[1],
+                self.0.node.0[2],
+                self.0.node.0[3],
+                self.0.node.0[4],
+                self.0.node.0[5],
+            ],
+        )
+    }
+
+    /// Finishes building, returning the assembled `Layout`.
+    pub fn into_layout(self) -> Layout {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_bytes_round_trips_through_be_bytes() {
+        let bytes = [
+            0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4,
+            0x30, 0xc8,
+        ];
+        let layout = Builder::from_bytes(bytes).into_layout();
+        assert_eq!(*layout.be_bytes().as_bytes(), bytes);
+    }
+
+    #[test]
+    fn with_version_and_variant_set_only_their_own_bits() {
+        let layout = Builder::from_bytes([0; 16])
+            .with_version(Version::RAND)
+            .with_variant(Variant::RFC)
+            .into_layout();
+        assert_eq!(layout.get_version(), Some(Version::RAND));
+        assert_eq!(layout.get_variant(), Some(Variant::RFC));
+    }
+
+    #[test]
+    fn from_fields_le_matches_byte_swapped_from_fields() {
+        let rest = [0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8];
+        let be = Builder::from_fields(0x6ba7b810, 0x9dad, 0x11d1, &rest).as_fields();
+        let le = Builder::from_fields_le(0x6ba7b810_u32.swap_bytes(), 0x9dad_u16.swap_bytes(), 0x11d1_u16.swap_bytes(), &rest)
+            .as_fields();
+        assert_eq!(be, le);
+    }
+}
@@ -0,0 +1,101 @@
+//! A stateful clock sequence generator for time-based (version-1,
+//! version-2) UUIDs.
+
+#[cfg(feature = "std")]
+use std::sync::{Mutex, OnceLock};
+
+/// Produces a `(timestamp, clock_sequence)` pair for time-based UUIDs.
+///
+/// Implementations must increment the clock sequence whenever the
+/// timestamp is observed to go backward (or stand still), so that
+/// duplicate timestamps still yield distinct UUIDs.
+pub trait ClockSequence {
+    /// Generates the next `(timestamp, clock_sequence)` pair for `now_100ns`.
+    fn generate_sequence(&mut self, now_100ns: u64) -> (u64, u16);
+}
+
+/// Holds the state needed to generate a monotonic clock sequence across
+/// repeated time-based UUID generations on the same node.
+#[derive(Debug, Default)]
+pub struct Context {
+    last_time: u64,
+    clock_seq: u16,
+}
+
+impl Context {
+    /// Creates a new context seeded with a starting clock sequence.
+    pub fn new(seed: u16) -> Self {
+        Context {
+            last_time: 0,
+            clock_seq: seed,
+        }
+    }
+}
+
+impl ClockSequence for Context {
+    fn generate_sequence(&mut self, now_100ns: u64) -> (u64, u16) {
+        if now_100ns <= self.last_time {
+            // The clock sequence field is 14 usable bits wide.
+            self.clock_seq = self.clock_seq.wrapping_add(1) & 0x3fff;
+        }
+        self.last_time = now_100ns;
+        (now_100ns, self.clock_seq)
+    }
+}
+
+/// The process-wide `Context` shared by the default (no explicit context)
+/// time-based generators, so that `UUID::new_from_sys_time()` calls that
+/// see the same (or an earlier) timestamp as the previous call still get
+/// distinct clock sequences.
+///
+/// Unavailable under `no_std`; callers without `std` drive a `Context`
+/// (or their own [`ClockSequence`]) explicitly instead.
+#[cfg(feature = "std")]
+pub(crate) fn global() -> &'static Mutex<Context> {
+    static CONTEXT: OnceLock<Mutex<Context>> = OnceLock::new();
+    CONTEXT.get_or_init(|| Mutex::new(Context::new(initial_seed())))
+}
+
+/// Starting clock sequence for [`global`]. Randomized under `rand_num` (so
+/// two processes racing the same node id don't start from the same
+/// sequence); without it, `std` alone still gets a working, merely
+/// non-randomized, shared `Context`.
+#[cfg(all(feature = "std", feature = "rand_num"))]
+fn initial_seed() -> u16 {
+    rand::random()
+}
+
+#[cfg(all(feature = "std", not(feature = "rand_num")))]
+fn initial_seed() -> u16 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_sequence_when_time_advances() {
+        let mut ctx = Context::new(0);
+        let (_, seq1) = ctx.generate_sequence(100);
+        let (_, seq2) = ctx.generate_sequence(200);
+        assert_eq!(seq1, seq2);
+    }
+
+    #[test]
+    fn bumps_sequence_when_time_regresses_or_repeats() {
+        let mut ctx = Context::new(0);
+        let (_, seq1) = ctx.generate_sequence(100);
+        let (_, seq2) = ctx.generate_sequence(100);
+        let (_, seq3) = ctx.generate_sequence(50);
+        assert_eq!(seq2, seq1 + 1);
+        assert_eq!(seq3, seq2 + 1);
+    }
+
+    #[test]
+    fn wraps_within_fourteen_bits() {
+        let mut ctx = Context::new(0x3fff);
+        let (_, seq) = ctx.generate_sequence(0);
+        assert_eq!(seq, 0);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{Layout, Node, Variant, Version, UUID};
+
+impl UUID {
+    /// New UUID version-8: application-defined data, with only the
+    /// version and variant bits overwritten so the result is a
+    /// well-formed UUID. All other bits are under the caller's control,
+    /// for embedding hashed identifiers, sharding keys, or non-standard
+    /// timestamps.
+    pub fn new_v8(mut bytes: [u8; 16]) -> Layout {
+        bytes[6] = (bytes[6] & 0x0f) | (Version::CUSTOM as u8) << 4;
+        bytes[8] = crate::pack_variant(bytes[8], Variant::RFC);
+
+        Layout {
+            field_low: u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            field_mid: u16::from_be_bytes([bytes[4], bytes[5]]),
+            field_high_and_version: u16::from_be_bytes([bytes[6], bytes[7]]),
+            clock_seq_high_and_reserved: bytes[8],
+            clock_seq_low: bytes[9],
+            node: Node([
+                bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+            ]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_v8_preserves_caller_data_outside_version_and_variant_bits() {
+        let uuid = UUID::new_v8([0xaa; 16]);
+        assert_eq!(uuid.get_version(), Some(Version::CUSTOM));
+        assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+        assert_eq!(uuid.field_low, 0xaaaa_aaaa);
+        assert_eq!(uuid.node.0, [0xaa; 6]);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{Builder, UUID};
+
+impl UUID {
+    /// Builds a `UUID` from its RFC 4122 field values, laid out in
+    /// big-endian (network) byte order — the layout this crate uses
+    /// everywhere else. Delegates to [`Builder`], which owns the field
+    /// assembly logic.
+    pub fn from_fields(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> UUID {
+        Builder::from_fields(d1, d2, d3, d4).into_layout().be_bytes()
+    }
+
+    /// Builds a `UUID` from field values where `d1`/`d2`/`d3` are
+    /// little-endian, matching the mixed-endian layout of a Windows
+    /// `GUID` struct (`Data1`/`Data2`/`Data3` native-endian, `Data4` a
+    /// plain byte array).
+    pub fn from_fields_le(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> UUID {
+        Builder::from_fields_le(d1, d2, d3, d4).into_layout().be_bytes()
+    }
+
+    /// Returns the field values of this `UUID` in big-endian order, the
+    /// inverse of [`UUID::from_fields`]. Named `be_fields` (rather than
+    /// `as_fields`) so it isn't mistaken for [`crate::Layout::as_fields`],
+    /// which returns a five-field tuple (clock sequence as one `u16`, node
+    /// as a [`crate::Node`]) in little-endian order.
+    pub fn be_fields(&self) -> (u32, u16, u16, [u8; 8]) {
+        Builder::from_bytes(*self.as_bytes()).as_fields()
+    }
+
+    /// Returns the field values of this `UUID` in the mixed-endian order
+    /// used by a Windows `GUID` struct, the inverse of
+    /// [`UUID::from_fields_le`].
+    pub fn le_fields(&self) -> (u32, u16, u16, [u8; 8]) {
+        let b = self.as_bytes();
+        (
+            u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+            u16::from_le_bytes([b[4], b[5]]),
+            u16::from_le_bytes([b[6], b[7]]),
+            [b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_fields_round_trips_through_be_fields() {
+        let d4 = [0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8];
+        let uuid = UUID::from_fields(0x6ba7b810, 0x9dad, 0x11d1, &d4);
+        assert_eq!(format!("{:x}", uuid), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        assert_eq!(uuid.be_fields(), (0x6ba7b810, 0x9dad, 0x11d1, d4));
+    }
+
+    #[test]
+    fn from_fields_le_round_trips_through_le_fields() {
+        let d4 = [0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8];
+        let uuid = UUID::from_fields_le(0x6ba7b810, 0x9dad, 0x11d1, &d4);
+        assert_eq!(uuid.le_fields(), (0x6ba7b810, 0x9dad, 0x11d1, d4));
+    }
+
+    #[test]
+    fn le_and_be_constructors_differ() {
+        let d4 = [0; 8];
+        let be = UUID::from_fields(0x6ba7b810, 0x9dad, 0x11d1, &d4);
+        let le = UUID::from_fields_le(0x6ba7b810, 0x9dad, 0x11d1, &d4);
+        assert_ne!(be, le);
+    }
+}
@@ -5,21 +5,53 @@
 //!
 //! ```toml
 //! [dependencies]
-//! simple-uuid = { version = "*", features = ["random"] }
+//! simple-uuid = "*"
 //! ```
 //!
 //! ```rust
+//! use simple_uuid::UUID;
+//! println!("{:x}", UUID::NAMESPACE_DNS);
+//! ```
+//!
+//! Generating a random (version-4) `UUID` additionally needs the
+//! `rand_num` feature:
+//!
+//! ```rust,ignore
 //! use simple_uuid::v4;
 //! println!("{}", v4!());
 //! ```
+//!
+//! By default this crate requires `std`, which powers the system-clock
+//! (`TimeStamp::new`) and global-context (`Context`) conveniences. Disabling
+//! default features compiles `Layout`/`UUID`, the name-based (v3/v5)
+//! hashing, `Builder`, and the `*_from_parts` constructors under
+//! `#![no_std]` with only `core`/`alloc`; the caller then supplies the
+//! clock and node id themselves.
 #![doc(html_root_url = "https://docs.rs/simple-uuid")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
+mod builder;
+mod context;
+mod custom;
+mod fields;
 mod name;
+mod parse;
 mod random;
+mod serde_impl;
 mod time;
 
+pub use builder::Builder;
+
+pub use context::{ClockSequence, Context};
+pub use parse::ParseError;
+
+use alloc::format;
+use alloc::string::{String, ToString};
 use core::fmt;
 use core::sync::atomic;
+#[cfg(feature = "std")]
 use std::time::SystemTime;
 
 /// Is 100-ns ticks between UNIX and UTC epochs.
@@ -117,18 +149,40 @@ impl Layout {
             0x03 => Some(Version::MD5),
             0x04 => Some(Version::RAND),
             0x05 => Some(Version::SHA1),
+            0x06 => Some(Version::SORT_MAC),
+            0x07 => Some(Version::SORT_RAND),
+            0x08 => Some(Version::CUSTOM),
             _ => None,
         }
     }
 
     /// Variant field of the current generated UUID.
     pub fn get_variant(&self) -> Option<Variant> {
-        match (self.clock_seq_high_and_reserved >> 4) & 0xf {
-            0x00 => Some(Variant::NCS),
-            0x01 => Some(Variant::RFC),
-            0x02 => Some(Variant::MS),
-            0x03 => Some(Variant::FUT),
-            _ => None,
+        decode_variant(self.clock_seq_high_and_reserved)
+    }
+
+    /// Assembles a time-based layout (version-1 or version-6) from
+    /// already-split timestamp/clock-sequence/node parts, the shared
+    /// `no_std`-compatible core behind [`UUID::v1_from_parts`] and
+    /// [`UUID::v6_from_parts`].
+    fn from_time_parts(utc: u64, clock_seq: (u8, u8), node: Node, version: Version) -> Self {
+        match version {
+            Version::SORT_MAC => Layout {
+                field_low: (utc >> 28) as u32,
+                field_mid: ((utc >> 12) & 0xffff) as u16,
+                field_high_and_version: (utc & 0xfff) as u16 | (Version::SORT_MAC as u16) << 12,
+                clock_seq_high_and_reserved: clock_seq.0,
+                clock_seq_low: clock_seq.1,
+                node,
+            },
+            _ => Layout {
+                field_low: (utc & 0xffff_ffff) as u32,
+                field_mid: ((utc >> 32 & 0xffff) as u16),
+                field_high_and_version: (utc >> 48 & 0xfff) as u16 | (Version::TIME as u16) << 12,
+                clock_seq_high_and_reserved: clock_seq.0,
+                clock_seq_low: clock_seq.1,
+                node,
+            },
         }
     }
 }
@@ -159,6 +213,12 @@ pub enum Version {
     RAND,
     /// The name-based version specified in `rfc4122`document that uses SHA-1 hashing.
     SHA1,
+    /// The time-ordered version using a reordered, MAC-address based timestamp (draft `rfc4122bis`).
+    SORT_MAC = 6,
+    /// The time-ordered version using a Unix-epoch timestamp and random data (draft `rfc4122bis`).
+    SORT_RAND = 7,
+    /// The custom/vendor version holding application-defined data (draft `rfc4122bis`).
+    CUSTOM = 8,
 }
 
 /// Represented by Coordinated Universal Time (UTC) as a count
@@ -167,14 +227,16 @@ pub enum Version {
 pub struct TimeStamp(u64);
 
 impl TimeStamp {
-    /// Generate new UTC timestamp.
+    /// Generate new UTC timestamp: the count of 100-ns intervals since
+    /// 00:00:00.00, 15 October 1582 (the start of the Gregorian calendar),
+    /// as required by `rfc4122` for version-1 timestamps.
+    #[cfg(feature = "std")]
     pub fn new() -> u64 {
-        let utc = SystemTime::now()
+        let unix_nanos = SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
             .unwrap()
-            .checked_add(std::time::Duration::from_nanos(UTC_EPOCH))
-            .unwrap()
-            .as_nanos();
+            .as_nanos() as u64;
+        let utc = (unix_nanos / 100).checked_add(UTC_EPOCH).unwrap();
         (utc & 0xffff_ffff_ffff_fff) as u64
     }
 }
@@ -207,6 +269,114 @@ impl UUID {
         0x6b, 0xa7, 0xb8, 0x14, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30,
         0xc8,
     ]);
+
+    /// The nil `UUID`, with all 128 bits set to zero.
+    pub const fn nil() -> UUID {
+        UUID([0; 16])
+    }
+
+    /// The max `UUID`, with all 128 bits set to one.
+    pub const fn max() -> UUID {
+        UUID([0xff; 16])
+    }
+
+    /// Builds a `UUID` from its raw 16-byte representation.
+    pub(crate) fn from_bytes(bytes: [u8; 16]) -> Self {
+        UUID(bytes)
+    }
+
+    /// Borrows the raw 16-byte representation of this `UUID`.
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// Returns `true` if this is the [`UUID::nil()`] value.
+    pub fn is_nil(&self) -> bool {
+        self.0 == [0; 16]
+    }
+
+    /// Version of this `UUID`, decoded directly from its bytes.
+    pub fn version(&self) -> Option<Version> {
+        match (self.0[6] >> 4) & 0xf {
+            0x01 => Some(Version::TIME),
+            0x02 => Some(Version::DCE),
+            0x03 => Some(Version::MD5),
+            0x04 => Some(Version::RAND),
+            0x05 => Some(Version::SHA1),
+            0x06 => Some(Version::SORT_MAC),
+            0x07 => Some(Version::SORT_RAND),
+            0x08 => Some(Version::CUSTOM),
+            _ => None,
+        }
+    }
+
+    /// Variant of this `UUID`, decoded directly from its bytes.
+    ///
+    /// Unlike the version field, the variant is a variable-length prefix
+    /// code (`rfc4122` section 4.1.1): `0xx` is `NCS`, `10x` is `RFC`,
+    /// `110` is `MS`, and `111` is `FUT`, so it cannot be read as a plain
+    /// 4-bit value the way [`UUID::version`] reads the version nibble. Every
+    /// generator in this crate packs the variant through [`pack_variant`],
+    /// the inverse of this decode, so this always agrees with
+    /// [`Layout::get_variant`].
+    pub fn variant(&self) -> Option<Variant> {
+        decode_variant(self.0[8])
+    }
+
+    /// Reconstructs the 100-ns timestamp embedded in a time-based
+    /// (version-1 or version-6) `UUID`, or `None` for any other version.
+    pub fn timestamp(&self) -> Option<TimeStamp> {
+        let field_low = u32::from_be_bytes([self.0[0], self.0[1], self.0[2], self.0[3]]);
+        let field_mid = u16::from_be_bytes([self.0[4], self.0[5]]);
+        let field_high_and_version = u16::from_be_bytes([self.0[6], self.0[7]]);
+        let low12 = (field_high_and_version & 0xfff) as u64;
+
+        match self.version()? {
+            Version::TIME => Some(TimeStamp(
+                (low12 << 48) | ((field_mid as u64) << 32) | (field_low as u64),
+            )),
+            Version::SORT_MAC => Some(TimeStamp(
+                ((field_low as u64) << 28) | ((field_mid as u64) << 12) | low12,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Builds a version-1 `Layout` from caller-supplied parts instead of
+    /// the system clock and local MAC address, so `no_std` callers (or
+    /// any caller that wants full control) can generate time-based UUIDs
+    /// without [`UUID::new_from_sys_time`]. `utc` is the 100-ns Gregorian
+    /// timestamp described by [`TimeStamp::new`], and `clock_seq` is a
+    /// 14-bit clock sequence, such as one produced by [`Context`].
+    pub fn v1_from_parts(utc: u64, node: Node, clock_seq: u16) -> Layout {
+        let clock_seq = split_clock_seq(clock_seq, Variant::RFC);
+        Layout::from_time_parts(utc, clock_seq, node, Version::TIME)
+    }
+
+    /// Builds a version-6 `Layout` from caller-supplied parts, the
+    /// `no_std`-compatible counterpart of [`UUID::new_v6`].
+    pub fn v6_from_parts(utc: u64, node: Node, clock_seq: u16) -> Layout {
+        let clock_seq = split_clock_seq(clock_seq, Variant::RFC);
+        Layout::from_time_parts(utc, clock_seq, node, Version::SORT_MAC)
+    }
+
+    /// Builds a version-7 `Layout` from a caller-supplied Unix-epoch
+    /// millisecond timestamp and 10 bytes of random data, the
+    /// `no_std`-compatible counterpart of [`UUID::new_v7`].
+    pub fn v7_from_parts(millis: u64, random: [u8; 10]) -> Layout {
+        let millis = millis & 0xffff_ffff_ffff;
+        Layout {
+            field_low: (millis >> 16) as u32,
+            field_mid: (millis & 0xffff) as u16,
+            field_high_and_version: ((random[0] as u16) << 8 | (random[1] as u16)) & 0xfff
+                | (Version::SORT_RAND as u16) << 12,
+            clock_seq_high_and_reserved: pack_variant(random[2], Variant::RFC),
+            clock_seq_low: random[3],
+            node: Node([
+                random[4], random[5], random[6], random[7], random[8], random[9],
+            ]),
+        }
+    }
 }
 
 impl fmt::LowerHex for UUID {
@@ -293,13 +463,53 @@ impl ClockSeq {
     }
 }
 
-fn clock_seq_high_and_reserved(s: u8) -> (u8, u8) {
-    let clock_seq = ClockSeq::new(rand::random::<u16>());
+#[cfg(feature = "std")]
+fn clock_seq_high_and_reserved(now_100ns: u64, variant: Variant) -> (u8, u8) {
+    let (_, clock_seq) = context::global().lock().unwrap().generate_sequence(now_100ns);
+    split_clock_seq(clock_seq, variant)
+}
+
+/// Splits a 14-bit clock sequence and a variant into the
+/// `clock_seq_high_and_reserved`/`clock_seq_low` byte pair used by
+/// [`Layout`], packing the variant through [`pack_variant`] so the result
+/// decodes correctly via [`Layout::get_variant`]/[`UUID::variant`].
+pub(crate) fn split_clock_seq(clock_seq: u16, variant: Variant) -> (u8, u8) {
     (
-        ((clock_seq >> 8) & 0xf) as u8 | s << 4,
+        pack_variant(((clock_seq >> 8) & 0x3f) as u8, variant),
         (clock_seq & 0xff) as u8,
     )
 }
+
+/// Packs `variant` into the high bits of `byte` as the RFC 4122 section
+/// 4.1.1 prefix code (`0xx` NCS, `10x` RFC, `110` MS, `111` FUT), keeping
+/// whichever low bits of `byte` that prefix leaves free. This is the single
+/// place every generator in this crate writes the variant field, so it
+/// always round-trips through [`decode_variant`].
+pub(crate) fn pack_variant(byte: u8, variant: Variant) -> u8 {
+    match variant {
+        Variant::NCS => byte & 0x7f,
+        Variant::RFC => (byte & 0x3f) | 0x80,
+        Variant::MS => (byte & 0x1f) | 0xc0,
+        Variant::FUT => (byte & 0x1f) | 0xe0,
+    }
+}
+
+/// Decodes the RFC 4122 section 4.1.1 variant prefix code from `byte`, the
+/// inverse of [`pack_variant`]. Shared by [`Layout::get_variant`] and
+/// [`UUID::variant`] so the two always agree.
+pub(crate) fn decode_variant(byte: u8) -> Option<Variant> {
+    if byte & 0x80 == 0x00 {
+        Some(Variant::NCS)
+    } else if byte & 0xc0 == 0x80 {
+        Some(Variant::RFC)
+    } else if byte & 0xe0 == 0xc0 {
+        Some(Variant::MS)
+    } else if byte & 0xe0 == 0xe0 {
+        Some(Variant::FUT)
+    } else {
+        None
+    }
+}
 /// The clock sequence is used to help avoid duplicates that could arise
 /// when the clock is set backwards in time or if the node ID changes.
 #[derive(Debug, PartialEq, Default, Copy, Clone)]
@@ -367,4 +577,62 @@ mod tests {
         let uuid = UUID::default();
         assert_eq!(uuid.to_string(), "00000000-0000-0000-0000-000000000000");
     }
+
+    #[test]
+    fn nil_and_max() {
+        assert!(UUID::nil().is_nil());
+        assert_eq!(UUID::nil(), UUID::default());
+        assert!(!UUID::max().is_nil());
+        assert_eq!(UUID::max().as_bytes(), &[0xff; 16]);
+    }
+
+    #[test]
+    fn version_and_variant_from_bytes() {
+        let uuid = UUID::NAMESPACE_DNS;
+        assert_eq!(uuid.version(), Some(Version::TIME));
+        assert_eq!(uuid.variant(), Some(Variant::RFC));
+    }
+
+    #[test]
+    #[cfg(feature = "mac")]
+    fn timestamp_round_trips_for_time_based_uuid() {
+        let uuid = UUID::from_utc(0x1234_u64).be_bytes();
+        assert_eq!(uuid.timestamp(), Some(TimeStamp(0x1234)));
+    }
+
+    #[test]
+    fn timestamp_is_none_for_non_time_based_uuid() {
+        assert_eq!(UUID::max().timestamp(), None);
+    }
+
+    #[test]
+    fn v1_from_parts_round_trips_through_be_bytes() {
+        let node = Node([0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80]);
+        let layout = UUID::v1_from_parts(0x1234_5678_9abc, node, 0x2a2a);
+        assert_eq!(layout.get_variant(), Some(Variant::RFC));
+        let uuid = layout.be_bytes();
+        assert_eq!(uuid.version(), Some(Version::TIME));
+        assert_eq!(uuid.variant(), Some(Variant::RFC));
+        assert_eq!(uuid.timestamp(), Some(TimeStamp(0x1234_5678_9abc)));
+    }
+
+    #[test]
+    fn v6_from_parts_round_trips_through_be_bytes() {
+        let node = Node([0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80]);
+        let layout = UUID::v6_from_parts(0x1234_5678_9abc, node, 0x2a2a);
+        assert_eq!(layout.get_variant(), Some(Variant::RFC));
+        let uuid = layout.be_bytes();
+        assert_eq!(uuid.version(), Some(Version::SORT_MAC));
+        assert_eq!(uuid.variant(), Some(Variant::RFC));
+        assert_eq!(uuid.timestamp(), Some(TimeStamp(0x1234_5678_9abc)));
+    }
+
+    #[test]
+    fn v7_from_parts_is_well_formed() {
+        let layout = UUID::v7_from_parts(0x1_7000_0000_000, [0xaa; 10]);
+        assert_eq!(layout.get_variant(), Some(Variant::RFC));
+        let uuid = layout.be_bytes();
+        assert_eq!(uuid.version(), Some(Version::SORT_RAND));
+        assert_eq!(uuid.variant(), Some(Variant::RFC));
+    }
 }
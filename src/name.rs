@@ -1,6 +1,8 @@
-#![cfg(any(feature = "hash_md5", feauture = "hash_sha1"))]
+#![cfg(any(feature = "hash_md5", feature = "hash_sha1"))]
 
-use std::convert::TryInto;
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryInto;
 
 use md5;
 use sha1::Sha1;
@@ -17,7 +19,7 @@ impl Layout {
             field_mid: (hash[4] as u16) << 8 | (hash[5] as u16),
             field_high_and_version: ((hash[6] as u16) << 8 | (hash[7] as u16)) & 0xfff
                 | (v as u16) << 12,
-            clock_seq_high_and_reserved: (hash[8] & 0xf) | (Variant::RFC as u8) << 4,
+            clock_seq_high_and_reserved: crate::pack_variant(hash[8], Variant::RFC),
             clock_seq_low: hash[9] as u8,
             node: Node([hash[10], hash[11], hash[12], hash[13], hash[14], hash[15]]),
         }
@@ -26,14 +28,12 @@ impl Layout {
 
 impl UUID {
     /// New UUID version-3 using md5 algorithme
-    #[doc(cfg(feature = "hash_md5"))]
     pub fn using_md5(data: &str, ns: UUID) -> Layout {
         let hash = md5::compute(Self::concat(data, ns)).0;
         Layout::hash_fields(hash, Version::MD5)
     }
 
     /// New UUID version-5 using sha1 algorithme
-    #[doc(cfg(feature = "hash_sha1"))]
     pub fn using_sha1(data: &str, ns: UUID) -> Layout {
         let hash = Sha1::from(Self::concat(data, ns)).digest().bytes()[..16]
             .try_into()
@@ -47,7 +47,6 @@ impl UUID {
 }
 
 /// `UUID` version-3
-#[doc(cfg(feature = "hash_md5"))]
 #[macro_export]
 macro_rules! v3 {
     ($data:expr, $ns:expr) => {
@@ -56,7 +55,6 @@ macro_rules! v3 {
 }
 
 /// `UUID` version-5
-#[doc(cfg(feature = "hash_sha1"))]
 #[macro_export]
 macro_rules! v5 {
     ($data:expr, $ns:expr) => {
@@ -0,0 +1,165 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::UUID;
+
+/// Positions (within a 36-byte hyphenated string) where a `-` is expected.
+const HYPHEN_POSITIONS: [usize; 4] = [8, 13, 18, 23];
+
+/// Describes why a string could not be parsed as a [`UUID`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The string (after trimming and stripping braces/URN prefix) is
+    /// neither 32 (simple form) nor 36 (hyphenated form) bytes long; holds
+    /// that length. Also returned for a `{` with no closing `}`.
+    InvalidLength(usize),
+    /// A non-hex-digit character was found at the given byte index.
+    InvalidCharacter(char, usize),
+    /// A `-` was found where a hex digit was expected, or vice-versa.
+    InvalidGroupSeparator(usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidLength(len) => {
+                write!(fmt, "invalid length {} for a UUID string", len)
+            }
+            ParseError::InvalidCharacter(c, idx) => {
+                write!(fmt, "invalid character {:?} at index {}", c, idx)
+            }
+            ParseError::InvalidGroupSeparator(idx) => {
+                write!(fmt, "invalid group separator at index {}", idx)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+impl UUID {
+    /// Parses a `UUID` out of `s`, accepting the hyphenated form
+    /// (`67e55044-10b1-426f-9247-bb680e5fe0c8`), the simple 32-hex-digit
+    /// form, the brace-wrapped form (`{...}`), and the URN form
+    /// (`urn:uuid:...`). Matching is case-insensitive.
+    pub fn parse(s: &str) -> Result<UUID, ParseError> {
+        let s = s.trim();
+
+        let s = if let Some(inner) = s.strip_prefix('{') {
+            inner
+                .strip_suffix('}')
+                .ok_or_else(|| ParseError::InvalidLength(s.len()))?
+        } else if s
+            .get(..9)
+            .map_or(false, |p| p.eq_ignore_ascii_case("urn:uuid:"))
+        {
+            &s[9..]
+        } else {
+            s
+        };
+
+        let bytes = s.as_bytes();
+        let has_hyphens = match bytes.len() {
+            32 => false,
+            36 => true,
+            len => return Err(ParseError::InvalidLength(len)),
+        };
+
+        let mut buf = [0u8; 16];
+        let mut byte_idx = 0;
+        let mut hi_nibble: Option<u8> = None;
+
+        for (i, &b) in bytes.iter().enumerate() {
+            let is_hyphen_slot = has_hyphens && HYPHEN_POSITIONS.contains(&i);
+
+            if b == b'-' {
+                if is_hyphen_slot {
+                    continue;
+                }
+                return Err(ParseError::InvalidGroupSeparator(i));
+            }
+            if is_hyphen_slot {
+                return Err(ParseError::InvalidGroupSeparator(i));
+            }
+
+            let digit = (b as char)
+                .to_digit(16)
+                .ok_or(ParseError::InvalidCharacter(b as char, i))? as u8;
+
+            match hi_nibble.take() {
+                None => hi_nibble = Some(digit),
+                Some(hi) => {
+                    buf[byte_idx] = (hi << 4) | digit;
+                    byte_idx += 1;
+                }
+            }
+        }
+
+        Ok(UUID::from_bytes(buf))
+    }
+}
+
+impl FromStr for UUID {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UUID::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_hyphenated() {
+        let uuid = UUID::parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        assert_eq!(format!("{:x}", uuid), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    }
+
+    #[test]
+    fn parse_simple() {
+        let uuid = UUID::parse("67e5504410b1426f9247bb680e5fe0c8").unwrap();
+        assert_eq!(format!("{:x}", uuid), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    }
+
+    #[test]
+    fn parse_braced() {
+        let uuid = UUID::parse("{67e55044-10b1-426f-9247-bb680e5fe0c8}").unwrap();
+        assert_eq!(format!("{:x}", uuid), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    }
+
+    #[test]
+    fn parse_urn() {
+        let uuid = UUID::parse("urn:uuid:67E55044-10B1-426F-9247-BB680E5FE0C8").unwrap();
+        assert_eq!(format!("{:x}", uuid), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    }
+
+    #[test]
+    fn parse_from_str_trait() {
+        let uuid: UUID = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+        assert_eq!(uuid, UUID::parse("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap());
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        assert_eq!(UUID::parse("67e55044"), Err(ParseError::InvalidLength(8)));
+    }
+
+    #[test]
+    fn rejects_invalid_character() {
+        assert_eq!(
+            UUID::parse("67e5504g-10b1-426f-9247-bb680e5fe0c8"),
+            Err(ParseError::InvalidCharacter('g', 7))
+        );
+    }
+
+    #[test]
+    fn rejects_misplaced_separator() {
+        assert_eq!(
+            UUID::parse("67e55044-10b-1426f-9247-bb680e5fe0c8"),
+            Err(ParseError::InvalidGroupSeparator(12))
+        );
+    }
+}
@@ -1,7 +1,8 @@
-#![doc(cfg(feature = "rand_num"))]
 #![cfg(feature = "rand_num")]
 
-use crate::{Layout, Node, Variant, Version, UUID};
+use std::time::SystemTime;
+
+use crate::{pack_variant, Layout, Node, Variant, Version, UUID};
 use rand as random;
 
 impl UUID {
@@ -16,15 +17,35 @@ impl UUID {
             field_mid: (rand[4] as u16) << 8 | (rand[5] as u16),
             field_high_and_version: ((rand[6] as u16) << 8 | (rand[7] as u16)) & 0xfff
                 | (Version::RAND as u16) << 12,
-            clock_seq_high_and_reserved: (rand[8] & 0xf) | (Variant::RFC as u8) << 4,
+            clock_seq_high_and_reserved: pack_variant(rand[8], Variant::RFC),
             clock_seq_low: rand[9] as u8,
             node: Node([rand[10], rand[11], rand[12], rand[13], rand[14], rand[15]]),
         }
     }
+
+    /// New UUID version-7: a Unix-epoch, millisecond-precision timestamp
+    /// followed by random data, so values sort lexicographically by
+    /// creation time.
+    pub fn new_v7() -> Layout {
+        let millis = (SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64)
+            & 0xffff_ffff_ffff;
+        let rand = random::random::<u128>().to_le_bytes();
+        Layout {
+            field_low: (millis >> 16) as u32,
+            field_mid: (millis & 0xffff) as u16,
+            field_high_and_version: ((rand[0] as u16) << 8 | (rand[1] as u16)) & 0xfff
+                | (Version::SORT_RAND as u16) << 12,
+            clock_seq_high_and_reserved: pack_variant(rand[2], Variant::RFC),
+            clock_seq_low: rand[3],
+            node: Node([rand[4], rand[5], rand[6], rand[7], rand[8], rand[9]]),
+        }
+    }
 }
 
 /// `UUID` version-4
-#[doc(cfg(feature = "rand_num"))]
 #[macro_export]
 macro_rules! v4 {
     () => {
@@ -32,6 +53,14 @@ macro_rules! v4 {
     };
 }
 
+/// `UUID` version-7
+#[macro_export]
+macro_rules! v7 {
+    () => {
+        format!("{:x}", $crate::UUID::new_v7().be_bytes())
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,4 +71,19 @@ mod tests {
         assert_eq!(uuid.get_version(), Some(Version::RAND));
         assert_eq!(uuid.get_variant(), Some(Variant::RFC));
     }
+
+    #[test]
+    fn new_v7() {
+        let uuid = UUID::new_v7();
+        assert_eq!(uuid.get_version(), Some(Version::SORT_RAND));
+        assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+    }
+
+    #[test]
+    fn new_v7_round_trips_through_string_form() {
+        let bytes = UUID::new_v7().be_bytes();
+        let parsed: UUID = format!("{:x}", bytes).parse().unwrap();
+        assert_eq!(parsed, bytes);
+        assert_eq!(parsed.version(), Some(Version::SORT_RAND));
+    }
 }
@@ -0,0 +1,95 @@
+#![cfg(feature = "serde")]
+
+use core::fmt;
+use std::convert::TryInto;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::UUID;
+
+impl Serialize for UUID {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&format!("{:x}", self))
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+struct UuidVisitor;
+
+impl<'de> Visitor<'de> for UuidVisitor {
+    type Value = UUID;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("a UUID string or a 16-byte array")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<UUID, E>
+    where
+        E: de::Error,
+    {
+        UUID::parse(v).map_err(de::Error::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<UUID, E>
+    where
+        E: de::Error,
+    {
+        let bytes: [u8; 16] = v
+            .try_into()
+            .map_err(|_| de::Error::invalid_length(v.len(), &"16 bytes"))?;
+        Ok(UUID::from_bytes(bytes))
+    }
+}
+
+impl<'de> Deserialize<'de> for UUID {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(UuidVisitor)
+        } else {
+            deserializer.deserialize_bytes(UuidVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_to_canonical_string_for_json() {
+        let uuid = UUID::NAMESPACE_DNS;
+        let json = serde_json::to_string(&uuid).unwrap();
+        assert_eq!(json, "\"6ba7b810-9dad-11d1-80b4-00c04fd430c8\"");
+    }
+
+    #[test]
+    fn deserializes_from_string_for_json() {
+        let uuid: UUID =
+            serde_json::from_str("\"6ba7b810-9dad-11d1-80b4-00c04fd430c8\"").unwrap();
+        assert_eq!(uuid, UUID::NAMESPACE_DNS);
+    }
+
+    #[test]
+    fn round_trips_through_binary_bytes() {
+        let uuid = UUID::NAMESPACE_URL;
+        let bytes = bincode::serialize(&uuid).unwrap();
+        let back: UUID = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(uuid, back);
+    }
+
+    #[test]
+    fn malformed_string_is_rejected_with_a_descriptive_error() {
+        let err = serde_json::from_str::<UUID>("\"not-a-uuid\"").unwrap_err();
+        assert!(err.to_string().contains("invalid"));
+    }
+}
@@ -1,8 +1,20 @@
 #![cfg(feature = "mac")]
 
+use std::time::{Duration, SystemTime};
+
 use mac_address;
 
-use crate::{Layout, Node, TimeStamp, Variant, Version, UUID};
+use crate::{ClockSequence, Layout, Node, TimeStamp, Variant, Version, UUID, UTC_EPOCH};
+
+/// Security-domain relative name, used by version-2 (DCE security) UUIDs.
+pub enum Domain {
+    /// Local domain for POSIX UIDs.
+    PERSON = 0,
+    /// Local domain for POSIX GIDs.
+    GROUP,
+    /// Site-defined domain.
+    ORG,
+}
 
 impl Layout {
     /// Get timestamp where the UUID generated in.
@@ -15,6 +27,16 @@ impl Layout {
         self.node
     }
 
+    /// Reconstructs the wall-clock instant embedded in this version-1
+    /// timestamp, reversing the encoding in [`Layout::time_fields`].
+    pub fn get_time(&self) -> SystemTime {
+        let intervals = (self.field_high_and_version as u64 & 0xfff) << 48
+            | (self.field_mid as u64) << 32
+            | self.field_low as u64;
+        let unix_nanos = (intervals - UTC_EPOCH) * 100;
+        SystemTime::UNIX_EPOCH + Duration::from_nanos(unix_nanos)
+    }
+
     fn time_fields(utc: u64, clock_seq: (u8, u8), node: Node) -> Self {
         Self {
             field_low: (utc & 0xffff_ffff) as u32,
@@ -30,23 +52,72 @@ impl Layout {
 impl UUID {
     /// New UUID version-1
     pub fn new_from_sys_time() -> Layout {
-        let clock_seq: (u8, u8) = crate::clock_seq_high_and_reserved(Variant::RFC as u8);
         let utc = TimeStamp::new();
+        let clock_seq: (u8, u8) = crate::clock_seq_high_and_reserved(utc, Variant::RFC);
         Layout::time_fields(utc, clock_seq, device_mac_addr())
     }
 
     /// New UUID with a user defined MAC-address.
     pub fn from_node(node: Node) -> Layout {
         let utc = TimeStamp::new();
-        let clock_seq = crate::clock_seq_high_and_reserved(Variant::RFC as u8);
+        let clock_seq = crate::clock_seq_high_and_reserved(utc, Variant::RFC);
         Layout::time_fields(utc, clock_seq, node)
     }
 
     /// New UUID with specific timestamp.
     pub fn from_utc(utc: u64) -> Layout {
-        let clock_seq = crate::clock_seq_high_and_reserved(Variant::RFC as u8);
+        let clock_seq = crate::clock_seq_high_and_reserved(utc, Variant::RFC);
+        Layout::time_fields(utc, clock_seq, device_mac_addr())
+    }
+
+    /// New UUID version-6: the same 100-ns Gregorian timestamp as
+    /// version-1, but with its fields reordered so the most-significant
+    /// bits come first, making it sort lexicographically by creation time.
+    pub fn new_v6() -> Layout {
+        let utc = TimeStamp::new();
+        let clock_seq: (u8, u8) = crate::clock_seq_high_and_reserved(utc, Variant::RFC);
+        Layout {
+            field_low: (utc >> 28) as u32,
+            field_mid: ((utc >> 12) & 0xffff) as u16,
+            field_high_and_version: (utc & 0xfff) as u16 | (Version::SORT_MAC as u16) << 12,
+            clock_seq_high_and_reserved: clock_seq.0,
+            clock_seq_low: clock_seq.1,
+            node: device_mac_addr(),
+        }
+    }
+
+    /// New UUID version-1 using a caller-supplied [`ClockSequence`] (such
+    /// as a [`Context`](crate::Context)) so bursts of generations on one
+    /// node get a monotonic, non-colliding clock sequence even if the
+    /// system clock goes backward.
+    pub fn new_from_context<C: ClockSequence>(ctx: &mut C) -> Layout {
+        let (utc, clock_seq) = ctx.generate_sequence(TimeStamp::new());
+        let clock_seq = crate::split_clock_seq(clock_seq, Variant::RFC);
         Layout::time_fields(utc, clock_seq, device_mac_addr())
     }
+
+    /// New UUID version-2 (DCE security) for `domain`, embedding `local_id`
+    /// (the POSIX UID for `Domain::PERSON`, the POSIX GID for
+    /// `Domain::GROUP`, or a site-defined id for `Domain::ORG`) in place of
+    /// the version-1 timestamp's low field, per `rfc4122` section 4.1.5.
+    /// Draws its timestamp and clock sequence from a caller-supplied
+    /// [`ClockSequence`], as [`UUID::new_from_context`] does, but the low
+    /// byte of the clock sequence is replaced by `domain`, so only its high
+    /// 6 bits appear in the result.
+    pub fn new_dce_with_context<C: ClockSequence>(
+        domain: Domain,
+        local_id: u32,
+        ctx: &mut C,
+    ) -> Layout {
+        let (utc, clock_seq) = ctx.generate_sequence(TimeStamp::new());
+        let clock_seq = crate::split_clock_seq(clock_seq, Variant::RFC);
+        let mut layout = Layout::time_fields(utc, clock_seq, device_mac_addr());
+        layout.field_high_and_version =
+            (layout.field_high_and_version & 0xfff) | (Version::DCE as u16) << 12;
+        layout.clock_seq_low = domain as u8;
+        layout.field_low = local_id;
+        layout
+    }
 }
 
 fn device_mac_addr() -> Node {
@@ -61,6 +132,14 @@ macro_rules! v1 {
     };
 }
 
+/// `UUID` version-6
+#[macro_export]
+macro_rules! v6 {
+    () => {
+        format!("{:x}", $crate::UUID::new_v6().be_bytes())
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,6 +151,36 @@ mod tests {
         assert_eq!(uuid.get_variant(), Some(Variant::RFC));
     }
 
+    #[test]
+    fn new_v6() {
+        let uuid = UUID::new_v6();
+        assert_eq!(uuid.get_version(), Some(Version::SORT_MAC));
+        assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+    }
+
+    #[test]
+    fn new_v6_round_trips_through_string_form() {
+        let bytes = UUID::new_v6().be_bytes();
+        let parsed: UUID = format!("{:x}", bytes).parse().unwrap();
+        assert_eq!(parsed, bytes);
+        assert_eq!(parsed.version(), Some(Version::SORT_MAC));
+        assert!(parsed.timestamp().is_some());
+    }
+
+    #[test]
+    fn repeated_timestamps_against_the_shared_context_bump_the_clock_sequence() {
+        // Drives the same global Context that new_from_sys_time() uses
+        // with a fixed, repeated timestamp (as context.rs's own tests
+        // do), instead of asserting on the real system clock: two
+        // back-to-back calls to new_from_sys_time() usually see the
+        // clock advance, in which case the sequence is correctly left
+        // unchanged and there's nothing to observe.
+        let now = 0x0123_4567_89ab_cdef_u64;
+        let (_, first) = crate::context::global().lock().unwrap().generate_sequence(now);
+        let (_, second) = crate::context::global().lock().unwrap().generate_sequence(now);
+        assert_ne!(first, second);
+    }
+
     #[test]
     fn new_uuid_from_user_defined_mac_address() {
         let uuid = UUID::from_node(Node([0x03, 0x2a, 0x35, 0x0d, 0x13, 0x80]));
@@ -85,4 +194,45 @@ mod tests {
         assert_eq!(uuid.get_version(), Some(Version::TIME));
         assert_eq!(uuid.get_timestamp(), 0x1234_u64);
     }
+
+    #[test]
+    fn get_time_reverses_the_gregorian_to_unix_offset() {
+        let uuid = UUID::from_utc(UTC_EPOCH);
+        assert_eq!(uuid.get_time(), SystemTime::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn new_timestamp_is_within_a_few_seconds_of_now() {
+        let now = UUID::new_from_sys_time().get_time();
+        let drift = now
+            .duration_since(SystemTime::now())
+            .unwrap_or_else(|e| e.duration())
+            .as_secs();
+        assert!(drift < 5);
+    }
+
+    #[test]
+    fn context_is_monotonic_under_repeated_timestamps() {
+        // new_from_context() draws its timestamp from the real system
+        // clock on every call, which usually advances between two
+        // back-to-back calls — in which case the clock sequence is
+        // correctly left unchanged. Drive the Context directly with a
+        // fixed, repeated timestamp instead (as context.rs's own tests
+        // do) to exercise the actual bump-on-no-advance behavior.
+        let mut ctx = crate::Context::new(0);
+        let now = 0x0123_4567_89ab_cdef_u64;
+        let (_, first) = ctx.generate_sequence(now);
+        let (_, second) = ctx.generate_sequence(now);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn new_dce_with_context() {
+        let mut ctx = crate::Context::new(0);
+        let uuid = UUID::new_dce_with_context(Domain::ORG, 0x1234_5678, &mut ctx);
+        assert_eq!(uuid.get_version(), Some(Version::DCE));
+        assert_eq!(uuid.get_variant(), Some(Variant::RFC));
+        assert_eq!(uuid.clock_seq_low, Domain::ORG as u8);
+        assert_eq!(uuid.field_low, 0x1234_5678);
+    }
 }